@@ -1,9 +1,68 @@
 use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 type OtherError = Box<dyn std::error::Error>;
 type OtherResult<T> = Result<T, OtherError>;
 
+const DEFAULT_HISTORY_FILE: &str = ".rustpython_history";
+
+fn home_dir() -> OtherResult<PathBuf> {
+    #[cfg(windows)]
+    let var = "USERPROFILE";
+    #[cfg(not(windows))]
+    let var = "HOME";
+
+    std::env::var_os(var)
+        .map(PathBuf::from)
+        .ok_or_else(|| format!("could not determine home directory ({} not set)", var).into())
+}
+
+fn expand_home(path: &str) -> OtherResult<PathBuf> {
+    match path.strip_prefix("~/") {
+        Some(rest) => Ok(home_dir()?.join(rest)),
+        None => Ok(PathBuf::from(path)),
+    }
+}
+
+/// Decides where the REPL history file lives, following one policy so
+/// embedders don't have to reinvent it:
+/// - `env_var` set and non-empty: use that path (`~/` is expanded against
+///   the home directory).
+/// - `env_var` set but empty: history is explicitly disabled (`Ok(None)`).
+/// - `env_var` unset: fall back to `~/.rustpython_history`.
+pub fn resolve_history_path(env_var: &str) -> OtherResult<Option<PathBuf>> {
+    match std::env::var(env_var) {
+        Ok(ref val) if val.is_empty() => Ok(None),
+        Ok(val) => expand_home(&val).map(Some),
+        Err(_) => Ok(Some(home_dir()?.join(DEFAULT_HISTORY_FILE))),
+    }
+}
+
+/// Sink for REPL chatter (prompts, error text), so embedders (WASM hosts,
+/// GUIs, test harnesses capturing output) can redirect it instead of it
+/// going straight to process-global stdio.
+pub trait Host {
+    fn stdout(&mut self, text: &str);
+    fn stderr(&mut self, text: &str);
+}
+
+/// The default `Host`: writes straight to the real stdout/stderr.
+pub struct BasicHost;
+
+impl Host for BasicHost {
+    fn stdout(&mut self, text: &str) {
+        use std::io::Write;
+        print!("{}", text);
+        let _ = io::stdout().flush();
+    }
+
+    fn stderr(&mut self, text: &str) {
+        use std::io::Write;
+        eprint!("{}", text);
+        let _ = io::stderr().flush();
+    }
+}
+
 pub enum ReadlineResult {
     Line(String),
     EOF,
@@ -13,6 +72,237 @@ pub enum ReadlineResult {
     Other(OtherError),
 }
 
+/// Emacs-style (the default) or Vi-style key bindings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditMode {
+    Emacs,
+    Vi,
+}
+
+/// How multiple completion candidates are cycled through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompletionType {
+    /// Show all candidates in a list, like bash.
+    List,
+    /// Cycle through candidates one at a time, like zsh.
+    Circular,
+}
+
+/// Knobs for the line editor that `Readline::new` used to hard-code.
+/// Construct with `ReadlineConfig::default()` and override individual
+/// fields, then pass to `Readline::with_config`.
+#[derive(Debug, Clone)]
+pub struct ReadlineConfig {
+    pub edit_mode: EditMode,
+    pub completion_type: CompletionType,
+    pub tab_stop: usize,
+    /// Don't add a line to history if it starts with a space.
+    pub history_ignore_space: bool,
+    pub max_history_size: usize,
+    /// Automatically call `add_history_entry` for every non-empty line
+    /// returned from `readline`.
+    pub auto_add_history: bool,
+    pub color_mode: bool,
+}
+
+impl Default for ReadlineConfig {
+    fn default() -> Self {
+        ReadlineConfig {
+            edit_mode: EditMode::Emacs,
+            completion_type: CompletionType::List,
+            tab_stop: 8,
+            history_ignore_space: false,
+            max_history_size: 1000,
+            auto_add_history: false,
+            color_mode: true,
+        }
+    }
+}
+
+#[cfg(unix)]
+fn stdout_is_tty() -> bool {
+    use std::os::unix::io::AsRawFd;
+    unsafe { libc::isatty(io::stdout().as_raw_fd()) != 0 }
+}
+
+#[cfg(windows)]
+fn stdout_is_tty() -> bool {
+    use std::os::windows::io::AsRawHandle;
+    use winapi::um::consoleapi::GetConsoleMode;
+    let mut mode = 0;
+    unsafe { GetConsoleMode(io::stdout().as_raw_handle() as _, &mut mode) != 0 }
+}
+
+// No `wasm32` variant: its only caller, `rustyline_readline::with_config`, is
+// itself `#[cfg(not(target_arch = "wasm32"))]`, so a stub here would just be
+// unreachable dead code on that target.
+
+/// Heuristics for deciding whether a chunk of Python source is a complete,
+/// ready-to-parse statement, or whether the REPL should keep asking for
+/// another physical line (unbalanced brackets, a trailing backslash, an
+/// unterminated triple-quoted string, or a suite-opening `:`).
+mod continuation {
+    #[derive(Clone, Copy, PartialEq)]
+    enum StrKind {
+        None,
+        Single,
+        Double,
+        TripleSingle,
+        TripleDouble,
+    }
+
+    /// Single-pass scan that tracks bracket depth and open-string state
+    /// together, so a `#` or a quote is only ever interpreted with the
+    /// context (already-in-a-string, already-in-a-comment) that the other
+    /// heuristic uses too. Comments run to the next `\n`; an unterminated
+    /// `'`/`"` string can't cross a `\n` (that's a syntax error, not a
+    /// continuation) but a triple-quoted one can.
+    struct Scan {
+        depth: i32,
+        string: StrKind,
+        /// `src` with comments (the `#` and everything up to the next `\n`)
+        /// removed but line breaks kept, so the line-oriented checks below
+        /// can't mistake a `:` or `\` inside a comment for real syntax.
+        stripped: String,
+    }
+
+    fn scan(src: &str) -> Scan {
+        let chars: Vec<char> = src.chars().collect();
+        let mut i = 0;
+        let mut depth = 0i32;
+        let mut string = StrKind::None;
+        let mut in_comment = false;
+        let mut stripped = String::with_capacity(src.len());
+        while i < chars.len() {
+            let c = chars[i];
+            if c == '\n' {
+                in_comment = false;
+                if string == StrKind::Single || string == StrKind::Double {
+                    string = StrKind::None;
+                }
+                stripped.push('\n');
+                i += 1;
+                continue;
+            }
+            if in_comment {
+                i += 1;
+                continue;
+            }
+            if string == StrKind::None && c == '#' {
+                in_comment = true;
+                i += 1;
+                continue;
+            }
+            match string {
+                StrKind::None => match c {
+                    '\'' if chars[i..].starts_with(&['\'', '\'', '\'']) => {
+                        string = StrKind::TripleSingle;
+                        stripped.push_str("'''");
+                        i += 3;
+                        continue;
+                    }
+                    '"' if chars[i..].starts_with(&['"', '"', '"']) => {
+                        string = StrKind::TripleDouble;
+                        stripped.push_str("\"\"\"");
+                        i += 3;
+                        continue;
+                    }
+                    '\'' => {
+                        string = StrKind::Single;
+                        stripped.push(c);
+                    }
+                    '"' => {
+                        string = StrKind::Double;
+                        stripped.push(c);
+                    }
+                    '(' | '[' | '{' => {
+                        depth += 1;
+                        stripped.push(c);
+                    }
+                    ')' | ']' | '}' => {
+                        depth -= 1;
+                        stripped.push(c);
+                    }
+                    _ => stripped.push(c),
+                },
+                StrKind::Single | StrKind::Double => {
+                    let quote = if string == StrKind::Single { '\'' } else { '"' };
+                    if c == '\\' {
+                        stripped.push(c);
+                        if let Some(&next) = chars.get(i + 1) {
+                            stripped.push(next);
+                        }
+                        i += 2;
+                        continue;
+                    } else {
+                        stripped.push(c);
+                        if c == quote {
+                            string = StrKind::None;
+                        }
+                    }
+                }
+                StrKind::TripleSingle | StrKind::TripleDouble => {
+                    let triple = if string == StrKind::TripleSingle {
+                        ['\'', '\'', '\'']
+                    } else {
+                        ['"', '"', '"']
+                    };
+                    if c == '\\' {
+                        stripped.push(c);
+                        if let Some(&next) = chars.get(i + 1) {
+                            stripped.push(next);
+                        }
+                        i += 2;
+                        continue;
+                    } else if chars[i..].starts_with(&triple) {
+                        string = StrKind::None;
+                        stripped.extend(triple.iter());
+                        i += 3;
+                        continue;
+                    } else {
+                        stripped.push(c);
+                    }
+                }
+            }
+            i += 1;
+        }
+        Scan {
+            depth,
+            string,
+            stripped,
+        }
+    }
+
+    pub fn is_complete(src: &str) -> bool {
+        let Scan {
+            depth,
+            string,
+            stripped,
+        } = scan(src);
+        if depth > 0 || string != StrKind::None {
+            return false;
+        }
+        if stripped.trim_end().ends_with('\\') {
+            return false;
+        }
+        if opens_suite(&stripped) {
+            return false;
+        }
+        true
+    }
+
+    /// Expects a comment-stripped source (see `Scan::stripped`), so a `:`
+    /// inside a comment isn't mistaken for one opening a suite.
+    fn opens_suite(stripped: &str) -> bool {
+        stripped
+            .lines()
+            .rev()
+            .find(|line| !line.trim().is_empty())
+            .map(|line| line.trim_end().ends_with(':'))
+            .unwrap_or(false)
+    }
+}
+
 #[allow(unused)]
 mod basic_readline {
     use super::*;
@@ -22,13 +312,33 @@ mod basic_readline {
 
     pub struct Readline<H: Helper> {
         helper: H,
+        host: Box<dyn super::Host>,
     }
 
     impl<H: Helper> Readline<H> {
         pub fn new(helper: H) -> Self {
-            Readline { helper }
+            Readline {
+                helper,
+                host: Box::new(super::BasicHost),
+            }
         }
 
+        /// There's no underlying editor to configure here, so `config` is
+        /// accepted and ignored.
+        pub fn with_config(helper: H, _config: super::ReadlineConfig) -> Self {
+            Self::new(helper)
+        }
+
+        /// Routes all REPL chatter (prompts, error text) through `host`
+        /// instead of the real stdout/stderr.
+        pub fn with_host(helper: H, host: Box<dyn super::Host>) -> Self {
+            Readline { helper, host }
+        }
+
+        /// There's no terminal highlighting here, so colors are always
+        /// stripped; this is a no-op.
+        pub fn set_prompt_colors(&mut self, _prompt_color: Option<String>, _dim_hints: bool) {}
+
         pub fn load_history(&mut self, _path: &Path) -> OtherResult<()> {
             Ok(())
         }
@@ -43,10 +353,7 @@ mod basic_readline {
 
         pub fn readline(&mut self, prompt: &str) -> ReadlineResult {
             use std::io::prelude::*;
-            print!("{}", prompt);
-            if let Err(e) = io::stdout().flush() {
-                return ReadlineResult::IO(e);
-            }
+            self.host.stdout(prompt);
 
             match io::stdin().lock().lines().next() {
                 Some(Ok(line)) => ReadlineResult::Line(line),
@@ -58,6 +365,40 @@ mod basic_readline {
                 },
             }
         }
+
+        /// Same as `readline`: this backend never echoes input itself and keeps
+        /// no history, and there's no terminal to put in raw mode here, so this
+        /// is just a plain stdin read.
+        pub fn read_password(&mut self, prompt: &str) -> ReadlineResult {
+            self.readline(prompt)
+        }
+
+        /// Loops on `readline`, concatenating lines with `\n`, until
+        /// `continuation::is_complete` reports the accumulated source is a
+        /// complete statement.
+        pub fn readline_continued(
+            &mut self,
+            prompt: &str,
+            continuation_prompt: &str,
+        ) -> ReadlineResult {
+            let mut buffer = String::new();
+            let mut current_prompt = prompt;
+            loop {
+                match self.readline(current_prompt) {
+                    ReadlineResult::Line(line) => {
+                        if !buffer.is_empty() {
+                            buffer.push('\n');
+                        }
+                        buffer.push_str(&line);
+                        if super::continuation::is_complete(&buffer) {
+                            return ReadlineResult::Line(buffer);
+                        }
+                        current_prompt = continuation_prompt;
+                    }
+                    other => return other,
+                }
+            }
+        }
     }
 }
 
@@ -68,23 +409,255 @@ mod rustyline_readline {
     pub trait Helper: rustyline::Helper {}
     impl<T: rustyline::Helper> Helper for T {}
 
+    /// Wraps a helper so rustyline's own multi-line editing kicks in: the
+    /// `continuation` heuristic reports brackets left open, a trailing
+    /// backslash, an unterminated triple-quoted string, or a suite-opening
+    /// `:` as incomplete, so the editor keeps the input open for another line
+    /// instead of submitting it. All other helper behavior (completion,
+    /// hinting, highlighting) is forwarded unchanged.
+    pub struct ContinuationHelper<H>(pub H);
+
+    impl<H: rustyline::Helper> rustyline::Helper for ContinuationHelper<H> {}
+
+    impl<H: rustyline::completion::Completer> rustyline::completion::Completer for ContinuationHelper<H> {
+        type Candidate = H::Candidate;
+
+        fn complete(
+            &self,
+            line: &str,
+            pos: usize,
+            ctx: &rustyline::Context,
+        ) -> rustyline::Result<(usize, Vec<Self::Candidate>)> {
+            self.0.complete(line, pos, ctx)
+        }
+    }
+
+    impl<H: rustyline::hint::Hinter> rustyline::hint::Hinter for ContinuationHelper<H> {
+        type Hint = H::Hint;
+
+        fn hint(&self, line: &str, pos: usize, ctx: &rustyline::Context) -> Option<Self::Hint> {
+            self.0.hint(line, pos, ctx)
+        }
+    }
+
+    impl<H: rustyline::highlight::Highlighter> rustyline::highlight::Highlighter for ContinuationHelper<H> {
+        fn highlight<'l>(&self, line: &'l str, pos: usize) -> std::borrow::Cow<'l, str> {
+            self.0.highlight(line, pos)
+        }
+
+        fn highlight_prompt<'p>(&self, prompt: &'p str, default: bool) -> std::borrow::Cow<'p, str> {
+            self.0.highlight_prompt(prompt, default)
+        }
+
+        fn highlight_hint<'h>(&self, hint: &'h str) -> std::borrow::Cow<'h, str> {
+            self.0.highlight_hint(hint)
+        }
+
+        fn highlight_candidate<'c>(
+            &self,
+            candidate: &'c str,
+            completion: rustyline::CompletionType,
+        ) -> std::borrow::Cow<'c, str> {
+            self.0.highlight_candidate(candidate, completion)
+        }
+
+        fn highlight_char(&self, line: &str, pos: usize) -> bool {
+            self.0.highlight_char(line, pos)
+        }
+    }
+
+    impl<H> rustyline::validate::Validator for ContinuationHelper<H> {
+        fn validate(
+            &self,
+            ctx: &mut rustyline::validate::ValidationContext,
+        ) -> rustyline::Result<rustyline::validate::ValidationResult> {
+            use rustyline::validate::ValidationResult;
+            if super::continuation::is_complete(ctx.input()) {
+                Ok(ValidationResult::Valid(None))
+            } else {
+                Ok(ValidationResult::Incomplete)
+            }
+        }
+    }
+
+    const HINT_DIM: &str = "\x1b[90m";
+    const COLOR_RESET: &str = "\x1b[0m";
+
+    /// Wraps a helper to render a separately-colored prompt and dim inline
+    /// hints, falling back to plain text when color is disabled or stdout
+    /// isn't a TTY. All other helper behavior is forwarded unchanged.
+    pub struct ColorHelper<H> {
+        inner: H,
+        color_enabled: bool,
+        prompt_color: Option<String>,
+        dim_hints: bool,
+    }
+
+    impl<H> ColorHelper<H> {
+        fn new(inner: H, color_enabled: bool) -> Self {
+            ColorHelper {
+                inner,
+                color_enabled,
+                prompt_color: None,
+                dim_hints: false,
+            }
+        }
+
+        fn set_colors(&mut self, prompt_color: Option<String>, dim_hints: bool) {
+            self.prompt_color = prompt_color;
+            self.dim_hints = dim_hints;
+        }
+    }
+
+    impl<H: rustyline::Helper> rustyline::Helper for ColorHelper<H> {}
+
+    impl<H: rustyline::completion::Completer> rustyline::completion::Completer for ColorHelper<H> {
+        type Candidate = H::Candidate;
+
+        fn complete(
+            &self,
+            line: &str,
+            pos: usize,
+            ctx: &rustyline::Context,
+        ) -> rustyline::Result<(usize, Vec<Self::Candidate>)> {
+            self.inner.complete(line, pos, ctx)
+        }
+    }
+
+    impl<H: rustyline::hint::Hinter> rustyline::hint::Hinter for ColorHelper<H> {
+        type Hint = H::Hint;
+
+        fn hint(&self, line: &str, pos: usize, ctx: &rustyline::Context) -> Option<Self::Hint> {
+            self.inner.hint(line, pos, ctx)
+        }
+    }
+
+    impl<H: rustyline::validate::Validator> rustyline::validate::Validator for ColorHelper<H> {
+        fn validate(
+            &self,
+            ctx: &mut rustyline::validate::ValidationContext,
+        ) -> rustyline::Result<rustyline::validate::ValidationResult> {
+            self.inner.validate(ctx)
+        }
+    }
+
+    impl<H: rustyline::highlight::Highlighter> rustyline::highlight::Highlighter for ColorHelper<H> {
+        fn highlight<'l>(&self, line: &'l str, pos: usize) -> std::borrow::Cow<'l, str> {
+            self.inner.highlight(line, pos)
+        }
+
+        fn highlight_prompt<'p>(&self, prompt: &'p str, default: bool) -> std::borrow::Cow<'p, str> {
+            if self.color_enabled {
+                if let Some(color) = &self.prompt_color {
+                    return std::borrow::Cow::Owned(format!("{}{}{}", color, prompt, COLOR_RESET));
+                }
+            }
+            self.inner.highlight_prompt(prompt, default)
+        }
+
+        fn highlight_hint<'h>(&self, hint: &'h str) -> std::borrow::Cow<'h, str> {
+            if self.color_enabled && self.dim_hints {
+                std::borrow::Cow::Owned(format!("{}{}{}", HINT_DIM, hint, COLOR_RESET))
+            } else {
+                self.inner.highlight_hint(hint)
+            }
+        }
+
+        fn highlight_candidate<'c>(
+            &self,
+            candidate: &'c str,
+            completion: rustyline::CompletionType,
+        ) -> std::borrow::Cow<'c, str> {
+            self.inner.highlight_candidate(candidate, completion)
+        }
+
+        fn highlight_char(&self, line: &str, pos: usize) -> bool {
+            self.inner.highlight_char(line, pos)
+        }
+    }
+
     /// Readline: the REPL
     pub struct Readline<H: Helper> {
-        repl: rustyline::Editor<H>,
+        repl: rustyline::Editor<ColorHelper<ContinuationHelper<H>>>,
+        host: Box<dyn super::Host>,
     }
 
     impl<H: Helper> Readline<H> {
         pub fn new(helper: H) -> Self {
+            Self::with_config(helper, super::ReadlineConfig::default())
+        }
+
+        /// Sets a prompt ANSI color (e.g. `"\x1b[32m"`) and whether inline
+        /// hints render dimmed. No-ops if color is disabled or stdout isn't
+        /// a TTY.
+        pub fn set_prompt_colors(&mut self, prompt_color: Option<String>, dim_hints: bool) {
+            if let Some(helper) = self.repl.helper_mut() {
+                helper.set_colors(prompt_color, dim_hints);
+            }
+        }
+
+        pub fn with_config(helper: H, config: super::ReadlineConfig) -> Self {
             use rustyline::*;
+
+            let edit_mode = match config.edit_mode {
+                super::EditMode::Emacs => EditMode::Emacs,
+                super::EditMode::Vi => EditMode::Vi,
+            };
+            let completion_type = match config.completion_type {
+                super::CompletionType::List => CompletionType::List,
+                super::CompletionType::Circular => CompletionType::Circular,
+            };
+
             let mut repl = Editor::with_config(
                 Config::builder()
-                    .completion_type(CompletionType::List)
-                    .tab_stop(8)
+                    .edit_mode(edit_mode)
+                    .completion_type(completion_type)
+                    .tab_stop(config.tab_stop)
+                    .history_ignore_space(config.history_ignore_space)
+                    .max_history_size(config.max_history_size)
+                    .auto_add_history(config.auto_add_history)
+                    .color_mode(if config.color_mode {
+                        ColorMode::Enabled
+                    } else {
+                        ColorMode::Disabled
+                    })
                     .bracketed_paste(false) // multi-line paste
                     .build(),
             );
-            repl.set_helper(Some(helper));
-            Readline { repl }
+            repl.set_helper(Some(ColorHelper::new(
+                ContinuationHelper(helper),
+                config.color_mode && stdout_is_tty(),
+            )));
+            Readline {
+                repl,
+                host: Box::new(super::BasicHost),
+            }
+        }
+
+        /// Like `new`, but writes the `read_password` prompt through `host`.
+        /// The prompts of `readline` and `readline_continued` are still drawn
+        /// by rustyline directly on the terminal, and the newline printed
+        /// after a password entry goes to the real stdout.
+        pub fn with_host(helper: H, host: Box<dyn super::Host>) -> Self {
+            let mut repl = Self::new(helper);
+            repl.host = host;
+            repl
+        }
+
+        /// The editor's `ContinuationHelper` already keeps itself in
+        /// multi-line entry mode and returns the fully assembled source from
+        /// a single `readline` call, so this just forwards to it.
+        /// `continuation_prompt` is accepted for parity with the
+        /// `basic_readline` backend (which has no editor to delegate
+        /// multi-line prompting to and must loop and concatenate itself) but
+        /// is unused here: rustyline shows `prompt` once, before the first
+        /// line, and draws no prompt on the lines that follow.
+        pub fn readline_continued(
+            &mut self,
+            prompt: &str,
+            _continuation_prompt: &str,
+        ) -> ReadlineResult {
+            self.readline(prompt)
         }
 
         pub fn load_history(&mut self, path: &Path) -> OtherResult<()> {
@@ -121,6 +694,72 @@ mod rustyline_readline {
                 Err(e) => ReadlineResult::Other(e.into()),
             }
         }
+
+        /// Like `readline`, but disables terminal echo while the line is typed
+        /// (e.g. for password prompts) and never records the result in history.
+        pub fn read_password(&mut self, prompt: &str) -> ReadlineResult {
+            self.host.stdout(prompt);
+
+            match read_password_raw() {
+                Ok(Some(line)) => ReadlineResult::Line(line),
+                Ok(None) => ReadlineResult::EOF,
+                Err(e) => match e.kind() {
+                    io::ErrorKind::Interrupted => ReadlineResult::Interrupt,
+                    io::ErrorKind::InvalidData => ReadlineResult::EncodingError,
+                    _ => ReadlineResult::IO(e),
+                },
+            }
+        }
+    }
+
+    // Uses `libc` directly (already pulled in for `stdout_is_tty`) rather
+    // than adding a dedicated termios crate dependency.
+    #[cfg(unix)]
+    fn read_password_raw() -> io::Result<Option<String>> {
+        use std::io::prelude::*;
+        use std::os::unix::io::AsRawFd;
+
+        let stdin = io::stdin();
+        let fd = stdin.as_raw_fd();
+
+        let mut original: libc::termios = unsafe { std::mem::zeroed() };
+        if unsafe { libc::tcgetattr(fd, &mut original) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let mut raw = original;
+        raw.c_lflag &= !libc::ECHO;
+        if unsafe { libc::tcsetattr(fd, libc::TCSANOW, &raw) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let result = stdin.lock().lines().next().transpose();
+
+        // always restore the terminal, even if reading the line failed
+        unsafe { libc::tcsetattr(fd, libc::TCSANOW, &original) };
+        println!();
+
+        result
+    }
+
+    #[cfg(windows)]
+    fn read_password_raw() -> io::Result<Option<String>> {
+        use std::io::prelude::*;
+        use winapi::um::consoleapi::{GetConsoleMode, SetConsoleMode};
+        use winapi::um::processenv::GetStdHandle;
+        use winapi::um::winbase::STD_INPUT_HANDLE;
+        use winapi::um::wincon::ENABLE_ECHO_INPUT;
+
+        let handle = unsafe { GetStdHandle(STD_INPUT_HANDLE) };
+        let mut original_mode = 0;
+        unsafe { GetConsoleMode(handle, &mut original_mode) };
+        unsafe { SetConsoleMode(handle, original_mode & !ENABLE_ECHO_INPUT) };
+
+        let result = io::stdin().lock().lines().next().transpose();
+
+        unsafe { SetConsoleMode(handle, original_mode) };
+        println!();
+
+        result
     }
 
     #[cfg(test)]
@@ -165,6 +804,8 @@ use basic_readline as readline_inner;
 use rustyline_readline as readline_inner;
 
 pub use readline_inner::Helper;
+#[cfg(not(target_arch = "wasm32"))]
+pub use rustyline_readline::ContinuationHelper;
 
 pub struct Readline<H: Helper>(readline_inner::Readline<H>);
 
@@ -172,17 +813,65 @@ impl<H: Helper> Readline<H> {
     pub fn new(helper: H) -> Self {
         Readline(readline_inner::Readline::new(helper))
     }
+    /// Like `new`, but with editor behavior (edit mode, completion style,
+    /// tab stop, history policy, color mode) controlled by `config` instead
+    /// of the hard-coded defaults.
+    pub fn with_config(helper: H, config: ReadlineConfig) -> Self {
+        Readline(readline_inner::Readline::with_config(helper, config))
+    }
+    /// Like `new`, but sends the backend's own prompt output through `host`
+    /// instead of the real stdout, so embedders (WASM hosts, GUIs, test
+    /// harnesses) can capture it. On the wasm backend that is every prompt;
+    /// on the rustyline backend it is only the `read_password` prompt, as
+    /// rustyline draws the `readline`/`readline_continued` prompts itself.
+    pub fn with_host(helper: H, host: Box<dyn Host>) -> Self {
+        Readline(readline_inner::Readline::with_host(helper, host))
+    }
+    /// Sets a prompt ANSI color (e.g. `"\x1b[32m"`) and whether inline hints
+    /// render dimmed. No-ops on backends/terminals that don't support it.
+    pub fn set_prompt_colors(&mut self, prompt_color: Option<String>, dim_hints: bool) {
+        self.0.set_prompt_colors(prompt_color, dim_hints)
+    }
     pub fn load_history(&mut self, path: &Path) -> OtherResult<()> {
         self.0.load_history(path)
     }
     pub fn save_history(&mut self, path: &Path) -> OtherResult<()> {
         self.0.save_history(path)
     }
+    /// Loads history from `resolve_history_path(env_var)`, doing nothing if
+    /// that resolves to `None` (history explicitly disabled).
+    pub fn load_history_env(&mut self, env_var: &str) -> OtherResult<()> {
+        match resolve_history_path(env_var)? {
+            Some(path) => self.load_history(&path),
+            None => Ok(()),
+        }
+    }
+    /// Saves history to `resolve_history_path(env_var)`, doing nothing if
+    /// that resolves to `None` (history explicitly disabled).
+    pub fn save_history_env(&mut self, env_var: &str) -> OtherResult<()> {
+        match resolve_history_path(env_var)? {
+            Some(path) => self.save_history(&path),
+            None => Ok(()),
+        }
+    }
     pub fn add_history_entry(&mut self, entry: &str) -> OtherResult<()> {
         self.0.add_history_entry(entry)
     }
     pub fn readline(&mut self, prompt: &str) -> ReadlineResult {
         self.0.readline(prompt)
     }
+    /// Read a line without echoing it to the terminal or adding it to history,
+    /// for prompts like `input()`/`getpass()` that shouldn't leak secrets.
+    pub fn read_password(&mut self, prompt: &str) -> ReadlineResult {
+        self.0.read_password(prompt)
+    }
+    /// Read a (possibly multi-line) logical statement, re-prompting with
+    /// `continuation_prompt` until brackets are balanced, a triple-quoted
+    /// string is closed, and the last line isn't a dangling `:` or `\`.
+    /// Only the wasm backend prints `continuation_prompt`; the rustyline
+    /// backend keeps editing the same input and ignores it.
+    pub fn readline_continued(&mut self, prompt: &str, continuation_prompt: &str) -> ReadlineResult {
+        self.0.readline_continued(prompt, continuation_prompt)
+    }
 }
 